@@ -2,17 +2,107 @@
 
 use tauri::{Manager, Emitter};
 use base64::Engine;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Mutex;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
 use tauri::{AppHandle, WebviewWindowBuilder, WebviewUrl};
 
+mod window_effects;
+
+use window_effects::BackdropKind;
+
 #[tauri::command]
 fn log_frontend(message: String) {
     println!("[frontend] {message}");
 }
 
+#[derive(Clone, Copy, Debug)]
+enum ImageFormat {
+    Png,
+    Jpeg,
+    Webp,
+}
+
+impl ImageFormat {
+    fn mime_type(self) -> &'static str {
+        match self {
+            ImageFormat::Png => "image/png",
+            ImageFormat::Jpeg => "image/jpeg",
+            ImageFormat::Webp => "image/webp",
+        }
+    }
+}
+
+fn parse_image_format(format: Option<&str>) -> Result<ImageFormat, String> {
+    match format.map(str::to_lowercase).as_deref() {
+        None | Some("png") => Ok(ImageFormat::Png),
+        Some("jpeg") | Some("jpg") => Ok(ImageFormat::Jpeg),
+        Some("webp") => Ok(ImageFormat::Webp),
+        Some(other) => Err(format!("Unsupported image format: {other}")),
+    }
+}
+
+// Encodes an RGBA buffer into the requested format. `quality` (0-100) only
+// applies to the lossy formats and is ignored for PNG.
+fn encode_image(
+    rgba: &[u8],
+    width: u32,
+    height: u32,
+    format: ImageFormat,
+    quality: u8,
+) -> Result<Vec<u8>, String> {
+    match format {
+        ImageFormat::Png => {
+            let mut data = Vec::new();
+            let mut encoder = png::Encoder::new(&mut data, width, height);
+            encoder.set_color(png::ColorType::Rgba);
+            encoder.set_depth(png::BitDepth::Eight);
+            let mut writer = encoder.write_header().map_err(|e| e.to_string())?;
+            writer.write_image_data(rgba).map_err(|e| e.to_string())?;
+            drop(writer);
+            Ok(data)
+        }
+        ImageFormat::Jpeg => {
+            // JPEG has no alpha channel, so drop it before encoding.
+            let rgb: Vec<u8> = rgba
+                .chunks_exact(4)
+                .flat_map(|px| [px[0], px[1], px[2]])
+                .collect();
+            let mut data = Vec::new();
+            let encoder = jpeg_encoder::Encoder::new(&mut data, quality);
+            encoder
+                .encode(&rgb, width as u16, height as u16, jpeg_encoder::ColorType::Rgb)
+                .map_err(|e| e.to_string())?;
+            Ok(data)
+        }
+        ImageFormat::Webp => {
+            let encoder = webp::Encoder::from_rgba(rgba, width, height);
+            Ok(encoder.encode(quality as f32).to_vec())
+        }
+    }
+}
+
+fn image_to_data_url(
+    rgba: &[u8],
+    width: u32,
+    height: u32,
+    format: ImageFormat,
+    quality: u8,
+) -> Result<String, String> {
+    let bytes = encode_image(rgba, width, height, format, quality)?;
+    let b64 = base64::engine::general_purpose::STANDARD.encode(&bytes);
+    Ok(format!("data:{};base64,{}", format.mime_type(), b64))
+}
+
 #[tauri::command]
-fn capture_fullscreen() -> Result<String, String> {
-    // Capture the primary screen and return a data URL.
+fn capture_fullscreen(
+    format: Option<String>,
+    quality: Option<u8>,
+    save_path: Option<String>,
+) -> Result<String, String> {
+    // Capture the primary screen and return a data URL, or write straight to
+    // disk and return the path when `save_path` is given.
     let screen = screenshots::Screen::from_point(0, 0).map_err(|e| e.to_string())?;
     let image = screen.capture().map_err(|e| e.to_string())?;
 
@@ -20,32 +110,267 @@ fn capture_fullscreen() -> Result<String, String> {
     let height = image.height() as u32;
     let buffer = image.rgba().clone();
 
-    let mut png_data = Vec::new();
+    let format = parse_image_format(format.as_deref())?;
+    let quality = quality.unwrap_or(90);
+
+    if let Some(path) = save_path {
+        let bytes = encode_image(&buffer, width, height, format, quality)?;
+        std::fs::write(&path, &bytes).map_err(|e| e.to_string())?;
+        return Ok(path);
+    }
+
+    image_to_data_url(&buffer, width, height, format, quality)
+}
+
+#[derive(serde::Serialize)]
+struct ScreenInfo {
+    index: usize,
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+    scale_factor: f32,
+}
+
+#[tauri::command]
+fn list_screens() -> Result<Vec<ScreenInfo>, String> {
+    let screens = screenshots::Screen::all().map_err(|e| e.to_string())?;
+    Ok(screens
+        .iter()
+        .enumerate()
+        .map(|(index, screen)| ScreenInfo {
+            index,
+            x: screen.display_info.x,
+            y: screen.display_info.y,
+            width: screen.display_info.width,
+            height: screen.display_info.height,
+            scale_factor: screen.display_info.scale_factor,
+        })
+        .collect())
+}
+
+#[tauri::command]
+fn capture_screen(index: usize) -> Result<String, String> {
+    let screens = screenshots::Screen::all().map_err(|e| e.to_string())?;
+    let screen = screens
+        .get(index)
+        .ok_or_else(|| format!("No screen at index {index}"))?;
+    let image = screen.capture().map_err(|e| e.to_string())?;
+
+    let width = image.width() as u32;
+    let height = image.height() as u32;
+    let buffer = image.rgba().clone();
+
+    image_to_data_url(&buffer, width, height, ImageFormat::Png, 100)
+}
+
+#[cfg(target_os = "windows")]
+fn capture_hwnd(hwnd: windows::Win32::Foundation::HWND) -> Result<(Vec<u8>, u32, u32), String> {
+    use windows::Win32::Foundation::RECT;
+    use windows::Win32::Graphics::Gdi::{
+        CreateCompatibleBitmap, CreateCompatibleDC, DeleteDC, DeleteObject, GetDC, GetDIBits,
+        ReleaseDC, SelectObject, BITMAPINFO, BITMAPINFOHEADER, BI_RGB, DIB_RGB_COLORS,
+    };
+    use windows::Win32::UI::WindowsAndMessaging::{GetClientRect, PrintWindow, PW_RENDERFULLCONTENT};
+
+    unsafe {
+        let mut rect = RECT::default();
+        GetClientRect(hwnd, &mut rect).map_err(|e| e.to_string())?;
+        let width = (rect.right - rect.left) as u32;
+        let height = (rect.bottom - rect.top) as u32;
+
+        if width == 0 || height == 0 {
+            return Err("Window has a zero-sized client area".into());
+        }
+
+        // BitBlt against GetDC(hwnd) only sees GDI content, not DWM-composited
+        // or hardware-accelerated child surfaces — which is exactly what a
+        // WebView2-backed window renders. PrintWindow with
+        // PW_RENDERFULLCONTENT asks DWM for the fully composited frame
+        // instead, so the webview's own window (and others like it) actually
+        // shows up.
+        let screen_dc = GetDC(None);
+        let mem_dc = CreateCompatibleDC(screen_dc);
+        let bitmap = CreateCompatibleBitmap(screen_dc, width as i32, height as i32);
+        let old_obj = SelectObject(mem_dc, bitmap);
+
+        let printed = PrintWindow(hwnd, mem_dc, PW_RENDERFULLCONTENT);
+
+        let mut buffer = vec![0u8; (width * height * 4) as usize];
+        let mut dibits_result = 0;
+        if printed.as_bool() {
+            let mut bitmap_info = BITMAPINFO {
+                bmiHeader: BITMAPINFOHEADER {
+                    biSize: std::mem::size_of::<BITMAPINFOHEADER>() as u32,
+                    biWidth: width as i32,
+                    // Negative height requests a top-down DIB so rows come
+                    // out in the same order our PNG/JPEG/WebP encoders expect.
+                    biHeight: -(height as i32),
+                    biPlanes: 1,
+                    biBitCount: 32,
+                    biCompression: BI_RGB.0,
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+
+            dibits_result = GetDIBits(
+                mem_dc,
+                bitmap,
+                0,
+                height,
+                Some(buffer.as_mut_ptr() as *mut _),
+                &mut bitmap_info,
+                DIB_RGB_COLORS,
+            );
+        }
+
+        SelectObject(mem_dc, old_obj);
+        let _ = DeleteObject(bitmap);
+        let _ = DeleteDC(mem_dc);
+        ReleaseDC(None, screen_dc);
+
+        if !printed.as_bool() {
+            return Err("PrintWindow failed while capturing window".into());
+        }
+        // GetDIBits returns the number of scanlines it copied, 0 on failure —
+        // without this check an occluded/minimized/access-denied window would
+        // silently come back as an all-zero buffer instead of an error.
+        if dibits_result == 0 {
+            return Err("GetDIBits failed while capturing window".into());
+        }
+
+        // GetDIBits returns BGRA; flip to RGBA to match the rest of the
+        // capture pipeline.
+        for px in buffer.chunks_exact_mut(4) {
+            px.swap(0, 2);
+        }
+
+        Ok((buffer, width, height))
+    }
+}
+
+#[tauri::command]
+fn capture_window(
+    app: AppHandle,
+    label: String,
+    format: Option<String>,
+    quality: Option<u8>,
+    save_path: Option<String>,
+) -> Result<String, String> {
+    #[cfg(target_os = "windows")]
     {
-        let mut encoder = png::Encoder::new(&mut png_data, width, height);
-        encoder.set_color(png::ColorType::Rgba);
-        encoder.set_depth(png::BitDepth::Eight);
-        let mut writer = encoder.write_header().map_err(|e| e.to_string())?;
-        writer
-            .write_image_data(&buffer)
-            .map_err(|e| e.to_string())?;
+        let window = app
+            .get_webview_window(&label)
+            .ok_or_else(|| format!("No window with label '{label}'"))?;
+        let hwnd = window.hwnd().map_err(|e| e.to_string())?;
+        let (buffer, width, height) = capture_hwnd(hwnd)?;
+
+        let format = parse_image_format(format.as_deref())?;
+        let quality = quality.unwrap_or(90);
+
+        if let Some(path) = save_path {
+            let bytes = encode_image(&buffer, width, height, format, quality)?;
+            std::fs::write(&path, &bytes).map_err(|e| e.to_string())?;
+            return Ok(path);
+        }
+
+        image_to_data_url(&buffer, width, height, format, quality)
     }
 
-    let b64 = base64::engine::general_purpose::STANDARD.encode(&png_data);
-    Ok(format!("data:image/png;base64,{}", b64))
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = (app, label, format, quality, save_path);
+        Err("capture_window is only supported on Windows".into())
+    }
+}
+
+// A source screen's placement within a captured SnipState buffer: `x`/`y`
+// are the screen's physical-pixel position on the virtual desktop, `offset_x`/
+// `offset_y` are where its pixels start inside the buffer, and the rest
+// describe the physical pixel dimensions actually captured for it.
+struct ScreenRegion {
+    x: i32,
+    y: i32,
+    offset_x: u32,
+    offset_y: u32,
+    width: u32,
+    height: u32,
+    scale_factor: f32,
+}
+
+// Captures every screen and stitches them into one buffer sized to the
+// bounding box of the virtual desktop, placing each screen's pixels at its
+// (x, y) offset relative to the bounding box origin. Returns the buffer
+// together with the virtual-desktop origin and per-screen regions so
+// callers can later map a logical selection back to the right pixels.
+fn composite_virtual_desktop(
+    screens: &[screenshots::Screen],
+) -> Result<(Vec<u8>, u32, u32, i32, i32, Vec<ScreenRegion>), String> {
+    if screens.is_empty() {
+        return Err("No screens found".into());
+    }
+
+    let min_x = screens.iter().map(|s| s.display_info.x).min().unwrap();
+    let min_y = screens.iter().map(|s| s.display_info.y).min().unwrap();
+    let max_x = screens
+        .iter()
+        .map(|s| s.display_info.x + s.display_info.width as i32)
+        .max()
+        .unwrap();
+    let max_y = screens
+        .iter()
+        .map(|s| s.display_info.y + s.display_info.height as i32)
+        .max()
+        .unwrap();
+
+    let width = (max_x - min_x) as u32;
+    let height = (max_y - min_y) as u32;
+    let mut buffer = vec![0u8; (width as usize) * (height as usize) * 4];
+    let mut regions = Vec::with_capacity(screens.len());
+
+    for screen in screens {
+        let image = screen.capture().map_err(|e| e.to_string())?;
+        let img_width = image.width();
+        let img_height = image.height();
+        let offset_x = (screen.display_info.x - min_x) as u32;
+        let offset_y = (screen.display_info.y - min_y) as u32;
+
+        for row in 0..img_height {
+            let src_start = (row * img_width * 4) as usize;
+            let src_end = src_start + (img_width * 4) as usize;
+            let dst_row = offset_y + row;
+            let dst_start = (dst_row * width * 4 + offset_x * 4) as usize;
+            let dst_end = dst_start + (img_width * 4) as usize;
+            buffer[dst_start..dst_end].copy_from_slice(&image.rgba()[src_start..src_end]);
+        }
+
+        regions.push(ScreenRegion {
+            x: screen.display_info.x,
+            y: screen.display_info.y,
+            offset_x,
+            offset_y,
+            width: img_width,
+            height: img_height,
+            scale_factor: screen.display_info.scale_factor,
+        });
+    }
+
+    Ok((buffer, width, height, min_x, min_y, regions))
 }
 
 struct SnipState {
     image: Vec<u8>,
     width: u32,
     height: u32,
+    regions: Vec<ScreenRegion>,
 }
 
 static SNIP_STATE: Mutex<Option<SnipState>> = Mutex::new(None);
 
 #[tauri::command]
-fn start_snip(app: AppHandle) -> Result<(), String> {
-    println!("[snip] start_snip invoked");
+fn start_snip(app: AppHandle, screen_index: Option<usize>) -> Result<(), String> {
+    println!("[snip] start_snip invoked (screen_index={screen_index:?})");
     // Hide main window to avoid capturing it in the screenshot.
     if let Some(main) = app.get_webview_window("main") {
         if let Err(e) = main.hide() {
@@ -53,25 +378,47 @@ fn start_snip(app: AppHandle) -> Result<(), String> {
         }
     }
 
-    let screen = screenshots::Screen::from_point(0, 0)
-        .map_err(|e| {
-            println!("[snip] Screen::from_point error: {e}");
-            e.to_string()
-        })?;
-    let image = screen.capture().map_err(|e| {
-        println!("[snip] screen.capture error: {e}");
+    let screens = screenshots::Screen::all().map_err(|e| {
+        println!("[snip] Screen::all error: {e}");
         e.to_string()
     })?;
-    let width = image.width() as u32;
-    let height = image.height() as u32;
-    let buffer = image.rgba().clone();
+
+    let (buffer, width, height, regions) = match screen_index {
+        Some(index) => {
+            let screen = screens
+                .get(index)
+                .ok_or_else(|| format!("No screen at index {index}"))?;
+            let image = screen.capture().map_err(|e| {
+                println!("[snip] screen.capture error: {e}");
+                e.to_string()
+            })?;
+            let width = image.width();
+            let height = image.height();
+            let region = ScreenRegion {
+                x: screen.display_info.x,
+                y: screen.display_info.y,
+                offset_x: 0,
+                offset_y: 0,
+                width,
+                height,
+                scale_factor: screen.display_info.scale_factor,
+            };
+            (image.rgba().clone(), width, height, vec![region])
+        }
+        None => {
+            let (buffer, width, height, _origin_x, _origin_y, regions) =
+                composite_virtual_desktop(&screens)?;
+            (buffer, width, height, regions)
+        }
+    };
 
     {
         let mut state = SNIP_STATE.lock().map_err(|e| e.to_string())?;
         *state = Some(SnipState {
-            image: buffer.clone(),
+            image: buffer,
             width,
             height,
+            regions,
         });
     }
 
@@ -131,18 +478,7 @@ fn get_snip_image() -> Result<String, String> {
         return Err("No snip state".into());
     };
 
-    let mut png_data = Vec::new();
-    {
-        let mut encoder = png::Encoder::new(&mut png_data, snip.width, snip.height);
-        encoder.set_color(png::ColorType::Rgba);
-        encoder.set_depth(png::BitDepth::Eight);
-        let mut writer = encoder.write_header().map_err(|e| e.to_string())?;
-        writer
-            .write_image_data(&snip.image)
-            .map_err(|e| e.to_string())?;
-    }
-    let b64 = base64::engine::general_purpose::STANDARD.encode(&png_data);
-    Ok(format!("data:image/png;base64,{}", b64))
+    image_to_data_url(&snip.image, snip.width, snip.height, ImageFormat::Png, 100)
 }
 
 #[tauri::command]
@@ -152,8 +488,11 @@ fn finish_snip(
     y: f32,
     width: f32,
     height: f32,
-    viewport_w: f32,
-    viewport_h: f32,
+    window_x: f32,
+    window_y: f32,
+    format: Option<String>,
+    quality: Option<u8>,
+    save_path: Option<String>,
 ) -> Result<(), String> {
     println!("[snip] finish_snip received selection x={x} y={y} w={width} h={height}");
     let mut state = SNIP_STATE.lock().map_err(|e| e.to_string())?;
@@ -165,42 +504,101 @@ fn finish_snip(
         return Err("Invalid selection".into());
     }
 
-    let scale_x = snip.width as f32 / viewport_w;
-    let scale_y = snip.height as f32 / viewport_h;
+    // `region.x`/`region.y`/`region.width`/`region.height` are always
+    // physical-pixel quantities in virtual-desktop coordinates (that's what
+    // `composite_virtual_desktop` indexes the buffer with), so every bound
+    // check below compares against them directly instead of converting
+    // through `scale_factor` first.
+    //
+    // `window_x`/`window_y` are the overlay window's own physical position;
+    // `x`/`y`/`width`/`height` are logical (CSS) pixels measured inside that
+    // window, so they first need scaling by whatever monitor the overlay
+    // itself sits on before they're comparable to anything physical.
+    let origin_region = snip
+        .regions
+        .iter()
+        .find(|r| {
+            window_x >= r.x as f32
+                && window_x < r.x as f32 + r.width as f32
+                && window_y >= r.y as f32
+                && window_y < r.y as f32 + r.height as f32
+        })
+        .or_else(|| snip.regions.first())
+        .ok_or("No capture regions available")?;
+
+    let overlay_scale = origin_region.scale_factor;
+    let abs_x = window_x + x * overlay_scale;
+    let abs_y = window_y + y * overlay_scale;
+    let sel_w = width * overlay_scale;
+    let sel_h = height * overlay_scale;
+
+    // Pick the source screen the selection starts on, so the crop is offset
+    // into that screen's pixels in the buffer. The selection itself was
+    // scaled above by the overlay's monitor factor only.
+    let region = snip
+        .regions
+        .iter()
+        .find(|r| {
+            abs_x >= r.x as f32
+                && abs_x < r.x as f32 + r.width as f32
+                && abs_y >= r.y as f32
+                && abs_y < r.y as f32 + r.height as f32
+        })
+        .or_else(|| snip.regions.first())
+        .ok_or("No capture regions available")?;
+
+    // A single crop can only be scaled one way, but spanning same-scale
+    // monitors is fine — `composite_virtual_desktop` already laid their
+    // pixels out contiguously in `snip.image`, so the flat crop below
+    // handles it correctly. Only reject when the far corner of the
+    // selection actually lands on a screen with a *different* scale
+    // factor, where there's no single correct pixel mapping.
+    let end_region = snip
+        .regions
+        .iter()
+        .find(|r| {
+            abs_x + sel_w > r.x as f32
+                && abs_x + sel_w <= r.x as f32 + r.width as f32
+                && abs_y + sel_h > r.y as f32
+                && abs_y + sel_h <= r.y as f32 + r.height as f32
+        })
+        .unwrap_or(region);
 
-    let sx = (x * scale_x).clamp(0.0, snip.width as f32) as u32;
-    let sy = (y * scale_y).clamp(0.0, snip.height as f32) as u32;
-    let sw = (width * scale_x).clamp(0.0, snip.width as f32 - sx as f32) as u32;
-    let sh = (height * scale_y).clamp(0.0, snip.height as f32 - sy as f32) as u32;
+    if (end_region.scale_factor - region.scale_factor).abs() > f32::EPSILON {
+        return Err("Selection spans monitors with different scale factors".into());
+    }
+
+    let sx = (region.offset_x as f32 + (abs_x - region.x as f32)).clamp(0.0, snip.width as f32) as u32;
+    let sy = (region.offset_y as f32 + (abs_y - region.y as f32)).clamp(0.0, snip.height as f32) as u32;
+    let sw = sel_w.clamp(0.0, snip.width as f32 - sx as f32) as u32;
+    let sh = sel_h.clamp(0.0, snip.height as f32 - sy as f32) as u32;
 
     if sw == 0 || sh == 0 {
         return Err("Selection too small".into());
     }
 
-    let mut png_data = Vec::new();
-    {
-        let mut encoder = png::Encoder::new(&mut png_data, sw, sh);
-        encoder.set_color(png::ColorType::Rgba);
-        encoder.set_depth(png::BitDepth::Eight);
-        let mut writer = encoder.write_header().map_err(|e| e.to_string())?;
-
-        let mut cropped = Vec::with_capacity((sw * sh * 4) as usize);
-        for row in sy..sy + sh {
-            let start = (row * snip.width * 4 + sx * 4) as usize;
-            let end = start + (sw * 4) as usize;
-            cropped.extend_from_slice(&snip.image[start..end]);
-        }
-
-        writer
-            .write_image_data(&cropped)
-            .map_err(|e| e.to_string())?;
+    let mut cropped = Vec::with_capacity((sw * sh * 4) as usize);
+    for row in sy..sy + sh {
+        let start = (row * snip.width * 4 + sx * 4) as usize;
+        let end = start + (sw * 4) as usize;
+        cropped.extend_from_slice(&snip.image[start..end]);
     }
 
-    let b64 = base64::engine::general_purpose::STANDARD.encode(&png_data);
-    let data_url = format!("data:image/png;base64,{}", b64);
+    let format = parse_image_format(format.as_deref())?;
+    let quality = quality.unwrap_or(90);
+
+    // When a save path is given, write the RGBA crop straight to disk
+    // instead of keeping a giant base64 data URL in memory.
+    let result = if let Some(path) = save_path {
+        let bytes = encode_image(&cropped, sw, sh, format, quality)?;
+        std::fs::write(&path, &bytes).map_err(|e| e.to_string())?;
+        path
+    } else {
+        image_to_data_url(&cropped, sw, sh, format, quality)?
+    };
 
     if let Some(main) = app.get_webview_window("main") {
-        main.emit("snip-complete", data_url)
+        main.emit("snip-complete", result)
             .map_err(|e| e.to_string())?;
         let _ = main.show();
         let _ = main.set_focus();
@@ -236,59 +634,140 @@ fn cancel_snip(app: AppHandle) -> Result<(), String> {
     Ok(())
 }
 
+// Shared tuning knobs for the background capture thread; control commands
+// only touch this and the stop flag, they never talk to the thread directly.
+struct StreamConfig {
+    fps: u32,
+    paused: bool,
+}
+
+static STREAM_STOP: AtomicBool = AtomicBool::new(false);
+static STREAM_CONFIG: Mutex<Option<StreamConfig>> = Mutex::new(None);
+static STREAM_HANDLE: Mutex<Option<JoinHandle<()>>> = Mutex::new(None);
+
+#[tauri::command]
+fn start_capture_stream(app: AppHandle, fps: u32, screen_index: usize) -> Result<(), String> {
+    println!("[stream] start_capture_stream invoked (fps={fps}, screen_index={screen_index})");
+    stop_capture_stream()?;
+
+    let screens = screenshots::Screen::all().map_err(|e| e.to_string())?;
+    let screen = *screens
+        .get(screen_index)
+        .ok_or_else(|| format!("No screen at index {screen_index}"))?;
+
+    STREAM_STOP.store(false, Ordering::SeqCst);
+    {
+        let mut config = STREAM_CONFIG.lock().map_err(|e| e.to_string())?;
+        *config = Some(StreamConfig { fps, paused: false });
+    }
+
+    let handle = thread::spawn(move || {
+        let mut last_frame: Option<Vec<u8>> = None;
+
+        while !STREAM_STOP.load(Ordering::SeqCst) {
+            let (paused, target_fps) = {
+                let config = STREAM_CONFIG.lock().unwrap();
+                match config.as_ref() {
+                    Some(c) => (c.paused, c.fps.max(1)),
+                    None => break,
+                }
+            };
+
+            if paused {
+                thread::sleep(Duration::from_millis(100));
+                continue;
+            }
+
+            let frame_start = Instant::now();
+
+            let image = match screen.capture() {
+                Ok(image) => image,
+                Err(e) => {
+                    println!("[stream] capture error: {e}");
+                    break;
+                }
+            };
+            let buffer = image.rgba().clone();
+
+            // Skip emitting if nothing changed since the last frame, so an
+            // idle screen doesn't flood the webview with identical frames.
+            if last_frame.as_ref() != Some(&buffer) {
+                match image_to_data_url(&buffer, image.width(), image.height(), ImageFormat::Png, 100) {
+                    Ok(data_url) => {
+                        let _ = app.emit("capture-frame", data_url);
+                    }
+                    Err(e) => println!("[stream] encode error: {e}"),
+                }
+                last_frame = Some(buffer);
+            }
+
+            let frame_budget = Duration::from_millis(1000 / target_fps as u64);
+            let elapsed = frame_start.elapsed();
+            if elapsed < frame_budget {
+                thread::sleep(frame_budget - elapsed);
+            }
+        }
+
+        println!("[stream] capture thread exiting");
+    });
+
+    {
+        let mut stored_handle = STREAM_HANDLE.lock().map_err(|e| e.to_string())?;
+        *stored_handle = Some(handle);
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+fn stop_capture_stream() -> Result<(), String> {
+    STREAM_STOP.store(true, Ordering::SeqCst);
+
+    {
+        let mut config = STREAM_CONFIG.lock().map_err(|e| e.to_string())?;
+        *config = None;
+    }
+
+    let handle = {
+        let mut stored_handle = STREAM_HANDLE.lock().map_err(|e| e.to_string())?;
+        stored_handle.take()
+    };
+
+    if let Some(handle) = handle {
+        println!("[stream] joining capture thread");
+        let _ = handle.join();
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+fn set_backdrop(window: tauri::WebviewWindow, kind: BackdropKind) -> Result<(), String> {
+    window_effects::set_backdrop(&window, kind)
+}
+
 fn main() {
     tauri::Builder::default()
         .invoke_handler(tauri::generate_handler![
             log_frontend,
             capture_fullscreen,
+            list_screens,
+            capture_screen,
+            capture_window,
             start_snip,
             get_snip_image,
             finish_snip,
-            cancel_snip
+            cancel_snip,
+            start_capture_stream,
+            stop_capture_stream,
+            set_backdrop
         ])
         .setup(|app| {
-            #[cfg(target_os = "windows")]
-            {
-                if let Some(window) = app.get_webview_window("main") {
-                    if let Ok(handle) = window.hwnd() {
-                        // Enable acrylic-style blur and dark mode so CSS backdrop-filter
-                        // can blend with the OS background instead of a flat color.
-                        unsafe {
-                            use windows::Win32::Foundation::HWND;
-                            use windows::Win32::Graphics::Dwm::{
-                                DwmSetWindowAttribute, DWMWINDOWATTRIBUTE,
-                            };
-
-                            const DWMWA_USE_IMMERSIVE_DARK_MODE: DWMWINDOWATTRIBUTE =
-                                DWMWINDOWATTRIBUTE(20);
-                            const DWMWA_SYSTEMBACKDROP_TYPE: DWMWINDOWATTRIBUTE =
-                                DWMWINDOWATTRIBUTE(38);
-                            // 3 = DWMSBT_TRANSIENTWINDOW (acrylic) on Win11+
-                            const DWMSBT_TRANSIENTWINDOW: u32 = 3;
-
-                            let hwnd = HWND(handle.0);
-                            let enable_dark: u32 = 1;
-                            let backdrop: u32 = DWMSBT_TRANSIENTWINDOW;
-
-                            // Dark mode helps the glass look consistent with the chrome.
-                            DwmSetWindowAttribute(
-                                hwnd,
-                                DWMWA_USE_IMMERSIVE_DARK_MODE,
-                                &enable_dark as *const _ as _,
-                                std::mem::size_of::<u32>() as u32,
-                            )
-                            .ok();
-
-                            // Acrylic-style blur behind the transparent window.
-                            DwmSetWindowAttribute(
-                                hwnd,
-                                DWMWA_SYSTEMBACKDROP_TYPE,
-                                &backdrop as *const _ as _,
-                                std::mem::size_of::<u32>() as u32,
-                            )
-                            .ok();
-                        }
-                    }
+            if let Some(window) = app.get_webview_window("main") {
+                // Acrylic today on Windows; a no-op stub on macOS/Linux until
+                // their native vibrancy/blur hooks are wired up.
+                if let Err(e) = window_effects::set_backdrop(&window, BackdropKind::Acrylic) {
+                    println!("[window_effects] failed to set initial backdrop: {e}");
                 }
             }
             Ok(())
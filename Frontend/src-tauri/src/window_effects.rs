@@ -0,0 +1,119 @@
+use serde::Deserialize;
+use tauri::WebviewWindow;
+
+/// The glass/blur styles a window can request. Not every platform can
+/// produce every kind natively; `set_backdrop` falls back to the closest
+/// native equivalent (or a no-op) rather than erroring.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BackdropKind {
+    None,
+    Mica,
+    Acrylic,
+    Vibrancy,
+    Blur,
+}
+
+/// Applies `kind` to `window` using whatever native mechanism the current
+/// platform offers.
+pub fn set_backdrop(window: &WebviewWindow, kind: BackdropKind) -> Result<(), String> {
+    #[cfg(target_os = "windows")]
+    {
+        return windows_backdrop::apply(window, kind);
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        return macos_backdrop::apply(window, kind);
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        return linux_backdrop::apply(window, kind);
+    }
+
+    #[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
+    {
+        let _ = (window, kind);
+        Ok(())
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod windows_backdrop {
+    use super::BackdropKind;
+    use tauri::WebviewWindow;
+    use windows::Win32::Foundation::HWND;
+    use windows::Win32::Graphics::Dwm::{DwmSetWindowAttribute, DWMWINDOWATTRIBUTE};
+
+    const DWMWA_USE_IMMERSIVE_DARK_MODE: DWMWINDOWATTRIBUTE = DWMWINDOWATTRIBUTE(20);
+    const DWMWA_SYSTEMBACKDROP_TYPE: DWMWINDOWATTRIBUTE = DWMWINDOWATTRIBUTE(38);
+
+    // DWM_SYSTEMBACKDROP_TYPE values, Win11+.
+    const DWMSBT_NONE: u32 = 1;
+    const DWMSBT_MAINWINDOW: u32 = 2; // Mica
+    const DWMSBT_TRANSIENTWINDOW: u32 = 3; // Acrylic
+    const DWMSBT_TABBEDWINDOW: u32 = 4; // Mica Alt, closest thing to a plain blur
+
+    pub fn apply(window: &WebviewWindow, kind: BackdropKind) -> Result<(), String> {
+        let handle = window.hwnd().map_err(|e| e.to_string())?;
+        let hwnd = HWND(handle.0);
+
+        // Windows has no dedicated "vibrancy" backdrop, so treat it the same
+        // as acrylic, which is the closest native look.
+        let backdrop: u32 = match kind {
+            BackdropKind::None => DWMSBT_NONE,
+            BackdropKind::Mica => DWMSBT_MAINWINDOW,
+            BackdropKind::Acrylic | BackdropKind::Vibrancy => DWMSBT_TRANSIENTWINDOW,
+            BackdropKind::Blur => DWMSBT_TABBEDWINDOW,
+        };
+
+        unsafe {
+            // Dark mode helps the glass look consistent with the chrome, but
+            // only while a backdrop is actually on — `None` explicitly turns
+            // it back off instead of just skipping the call, otherwise a
+            // later `set_backdrop(window, BackdropKind::None)` would leave
+            // dark mode stuck on from an earlier Mica/Acrylic/etc. call.
+            let enable_dark: u32 = if matches!(kind, BackdropKind::None) { 0 } else { 1 };
+            DwmSetWindowAttribute(
+                hwnd,
+                DWMWA_USE_IMMERSIVE_DARK_MODE,
+                &enable_dark as *const _ as _,
+                std::mem::size_of::<u32>() as u32,
+            )
+            .map_err(|e| e.to_string())?;
+
+            DwmSetWindowAttribute(
+                hwnd,
+                DWMWA_SYSTEMBACKDROP_TYPE,
+                &backdrop as *const _ as _,
+                std::mem::size_of::<u32>() as u32,
+            )
+            .map_err(|e| e.to_string())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod macos_backdrop {
+    use super::BackdropKind;
+    use tauri::WebviewWindow;
+
+    // TODO: wire up NSVisualEffectView vibrancy via objc2/cocoa bindings.
+    pub fn apply(_window: &WebviewWindow, _kind: BackdropKind) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod linux_backdrop {
+    use super::BackdropKind;
+    use tauri::WebviewWindow;
+
+    // TODO: wire up compositor blur hints (e.g. KWin/Mutter blur regions).
+    pub fn apply(_window: &WebviewWindow, _kind: BackdropKind) -> Result<(), String> {
+        Ok(())
+    }
+}